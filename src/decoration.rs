@@ -0,0 +1,312 @@
+//! Decorated intervals, which pair a [`RealInterval`] with a [`Decoration`] tracking
+//! how trustworthy the enclosure is. This mirrors inari's `DecInterval` concept: rather
+//! than panicking when an operation strays outside its domain (as the bare `RealInterval`
+//! methods do), a [`DecInterval`] downgrades its decoration and keeps going, letting chains
+//! of operations be inspected for validity only at the end.
+
+use std::ops::*;
+
+use crate::*;
+
+/// Describes how trustworthy a [`DecInterval`]'s enclosure is, from weakest to strongest.
+/// Decorations only ever drop along a chain of operations; they can never rise again.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Decoration {
+    /// Not an interval. Produced by invalid operations (e.g. those yielding `NaN` bounds).
+    Ill,
+    /// Defined nowhere in particular; the safest fallback when nothing stronger can be proven,
+    /// such as when the input domain only partially overlaps the function's domain.
+    Trv,
+    /// The function is defined over the entire input interval, but may be discontinuous
+    /// or unbounded there.
+    Def,
+    /// The function is defined and continuous over the entire input interval, but the
+    /// result may be unbounded.
+    Dac,
+    /// Common: the function is defined, continuous, and bounded over the entire input
+    /// interval. The strongest decoration.
+    #[default]
+    Com
+}
+
+impl Decoration {
+    /// Returns the weaker (less trustworthy) of the two decorations. Used to propagate
+    /// decorations through binary operations, where the result can only be as trustworthy
+    /// as its least trustworthy input.
+    pub fn weaker(self, other: Self) -> Self {
+        self.min(other)
+    }
+
+    /// Caps this decoration at `Dac`, used when an operation's result is unbounded even
+    /// though it remains defined and continuous.
+    fn cap_unbounded(self) -> Self {
+        self.weaker(Decoration::Dac)
+    }
+}
+
+impl std::fmt::Display for Decoration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Decoration::Ill => "ill",
+            Decoration::Trv => "trv",
+            Decoration::Def => "def",
+            Decoration::Dac => "dac",
+            Decoration::Com => "com"
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// A [`RealInterval`] paired with a [`Decoration`] tracking whether the enclosure can be
+/// trusted. Operations on `DecInterval` never panic on out-of-domain input; they instead
+/// downgrade the decoration, so a whole chain of computation can be inspected for validity
+/// just once at the end.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DecInterval {
+    /// The enclosing interval. Only meaningful when [`Self::decoration`] is not `Ill`.
+    pub interval: RealInterval,
+    /// How trustworthy `interval` is as an enclosure.
+    pub decoration: Decoration
+}
+
+impl DecInterval {
+    /// Wraps an interval with the strongest decoration it can carry: `Com`, or `Dac` if
+    /// the interval is unbounded (`Ill` if a bound is `NaN`). Use this for intervals
+    /// coming directly from known-good data, before any domain-restricted operation
+    /// has had a chance to downgrade them.
+    pub fn new(interval: RealInterval) -> Self {
+        Self { interval, decoration: refine(Decoration::Com, interval) }
+    }
+
+    /// Applies a scalar power to this interval, mirroring [`RealInterval::powf`] but
+    /// downgrading the decoration instead of panicking when the interval reaches below zero.
+    pub fn powf(self, value: f32) -> Self {
+        self.restrict_to_non_negative(|interval| interval.powf(value))
+    }
+
+    /// Applies an integral scalar power to this interval, mirroring [`RealInterval::powi`].
+    /// For a non-negative exponent this is defined over the whole real line, so the
+    /// decoration only drops if the result itself becomes unbounded or invalid. A negative
+    /// exponent is undefined at zero, so an interval containing zero downgrades to `Trv`.
+    pub fn powi(self, value: i32) -> Self {
+        let interval = self.interval.powi(value);
+        let restriction = if value < 0 && self.interval.contains(0.0) { Decoration::Trv } else { Decoration::Com };
+        let decoration = refine(self.decoration.weaker(restriction), interval);
+        Self { interval, decoration }
+    }
+
+    /// Takes the square root of this interval, mirroring [`RealInterval::sqrt`] but
+    /// downgrading the decoration instead of panicking when the interval reaches below zero.
+    pub fn sqrt(self) -> Self {
+        self.restrict_to_non_negative(RealInterval::sqrt)
+    }
+
+    /// Takes the natural logarithm of this interval, mirroring [`RealInterval::ln`] but
+    /// downgrading the decoration instead of panicking when the interval reaches zero or
+    /// below, since the logarithm is undefined at zero.
+    pub fn ln(self) -> Self {
+        if self.interval.max <= 0.0 {
+            Self { interval: self.interval, decoration: Decoration::Ill }
+        }
+        else {
+            let result = self.restrict_to_non_negative(RealInterval::ln);
+            let restriction = if self.interval.min <= 0.0 { Decoration::Trv } else { Decoration::Com };
+            Self { decoration: result.decoration.weaker(restriction), ..result }
+        }
+    }
+
+    /// Takes `e` raised to the power of this interval, mirroring [`RealInterval::exp`].
+    /// This is defined and bounded everywhere it produces a finite result.
+    pub fn exp(self) -> Self {
+        let interval = self.interval.exp();
+        let decoration = refine(self.decoration.weaker(Decoration::Com), interval);
+        Self { interval, decoration }
+    }
+
+    /// Takes the sine of this interval, mirroring [`RealInterval::sin`].
+    /// This is defined, continuous, and bounded over the whole real line.
+    pub fn sin(self) -> Self {
+        let interval = self.interval.sin();
+        let decoration = refine(self.decoration.weaker(Decoration::Com), interval);
+        Self { interval, decoration }
+    }
+
+    /// Takes the cosine of this interval, mirroring [`RealInterval::cos`].
+    /// This is defined, continuous, and bounded over the whole real line.
+    pub fn cos(self) -> Self {
+        let interval = self.interval.cos();
+        let decoration = refine(self.decoration.weaker(Decoration::Com), interval);
+        Self { interval, decoration }
+    }
+
+    /// Shared implementation for functions (like `sqrt`) whose domain is `[0, +inf)`, also
+    /// used by `ln` after it has handled its undefined point at zero:
+    /// clamps to the non-negative portion of the interval and downgrades the decoration to
+    /// `Trv` if part of the domain was out of range, or to `Ill` if all of it was.
+    fn restrict_to_non_negative(self, f: impl Fn(RealInterval) -> RealInterval) -> Self {
+        if self.interval.max < 0.0 {
+            Self { interval: self.interval, decoration: Decoration::Ill }
+        }
+        else if self.interval.min < 0.0 {
+            let domain = RealInterval::min_max(0.0, self.interval.max);
+            let interval = f(domain);
+            let decoration = refine(self.decoration.weaker(Decoration::Trv), interval);
+            Self { interval, decoration }
+        }
+        else {
+            let interval = f(self.interval);
+            let decoration = refine(self.decoration.weaker(Decoration::Com), interval);
+            Self { interval, decoration }
+        }
+    }
+}
+
+/// Downgrades `decoration` based on the interval it ended up describing: `NaN` bounds
+/// force `Ill`, and unbounded (infinite) bounds cap it at `Dac`.
+fn refine(decoration: Decoration, interval: RealInterval) -> Decoration {
+    if interval.min.is_nan() || interval.max.is_nan() {
+        Decoration::Ill
+    }
+    else if !interval.min.is_finite() || !interval.max.is_finite() {
+        decoration.cap_unbounded()
+    }
+    else {
+        decoration
+    }
+}
+
+impl Add<f32> for DecInterval {
+    type Output = Self;
+
+    fn add(self, rhs: f32) -> Self::Output {
+        let interval = self.interval + rhs;
+        Self { interval, decoration: refine(self.decoration, interval) }
+    }
+}
+
+impl Sub<f32> for DecInterval {
+    type Output = Self;
+
+    fn sub(self, rhs: f32) -> Self::Output {
+        let interval = self.interval - rhs;
+        Self { interval, decoration: refine(self.decoration, interval) }
+    }
+}
+
+impl Mul<f32> for DecInterval {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        let interval = self.interval * rhs;
+        Self { interval, decoration: refine(self.decoration, interval) }
+    }
+}
+
+impl Div<f32> for DecInterval {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        let interval = self.interval / rhs;
+        let decoration = self.decoration.weaker(if rhs == 0.0 { Decoration::Trv } else { Decoration::Com });
+        Self { interval, decoration: refine(decoration, interval) }
+    }
+}
+
+impl Add for DecInterval {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let interval = self.interval + rhs.interval;
+        let decoration = self.decoration.weaker(rhs.decoration);
+        Self { interval, decoration: refine(decoration, interval) }
+    }
+}
+
+impl Sub for DecInterval {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let interval = self.interval - rhs.interval;
+        let decoration = self.decoration.weaker(rhs.decoration);
+        Self { interval, decoration: refine(decoration, interval) }
+    }
+}
+
+impl Mul for DecInterval {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let interval = self.interval * rhs.interval;
+        let decoration = self.decoration.weaker(rhs.decoration);
+        Self { interval, decoration: refine(decoration, interval) }
+    }
+}
+
+impl Div for DecInterval {
+    type Output = Self;
+
+    /// Divides this decorated interval by another. If the divisor's interval contains
+    /// zero, the decoration downgrades to `Trv`, since the quotient may not be defined
+    /// (or continuous) everywhere.
+    fn div(self, rhs: Self) -> Self::Output {
+        let interval = self.interval / rhs.interval;
+        let mut decoration = self.decoration.weaker(rhs.decoration);
+
+        if rhs.interval.contains(0.0) {
+            decoration = decoration.weaker(Decoration::Trv);
+        }
+
+        Self { interval, decoration: refine(decoration, interval) }
+    }
+}
+
+impl Neg for DecInterval {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { interval: -self.interval, decoration: self.decoration }
+    }
+}
+
+impl std::fmt::Display for DecInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}_{}", self.interval, self.decoration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decoration_propagation() {
+        let defined = DecInterval::new(RealInterval::min_max(1.0, 4.0));
+        assert_eq!(Decoration::Com, defined.decoration);
+
+        let rooted = defined.powf(0.5);
+        assert_eq!(Decoration::Com, rooted.decoration);
+
+        let straddling = DecInterval::new(RealInterval::min_max(-1.0, 4.0));
+        let partial = straddling.powf(0.5);
+        assert_eq!(Decoration::Trv, partial.decoration);
+
+        let entirely_negative = DecInterval::new(RealInterval::min_max(-4.0, -1.0));
+        let invalid = entirely_negative.powf(0.5);
+        assert_eq!(Decoration::Ill, invalid.decoration);
+
+        let zero = RealInterval::min_max(-1.0, 1.0);
+        let divided = defined / DecInterval::new(zero);
+        assert_eq!(Decoration::Trv, divided.decoration);
+
+        // Decorations can only drop, never rise, once a chain has been downgraded.
+        let chained = partial.powf(2.0);
+        assert!(chained.decoration <= Decoration::Trv);
+
+        // Dividing by zero must weaken toward `Trv`, never raise a decoration that
+        // already dropped below it.
+        let already_ill = DecInterval { interval: RealInterval::min_max(1.0, 4.0), decoration: Decoration::Ill };
+        assert_eq!(Decoration::Ill, (already_ill / 0.0).decoration);
+        assert_eq!(Decoration::Trv, (defined / 0.0).decoration);
+    }
+}
@@ -2,44 +2,90 @@
 //! interval manipulation. Scalar operations, arithmetic operations, and set operations
 //! on intervals are all supported. The following is a simple example of how to use
 //! intervals:
-//! 
+//!
 //! ```
 //! # use real_interval::*;
 //! let interval = RealInterval::min_max(-1.0, 2.0);
 //! let shifted_interval = interval + 0.5;
 //! let expanded_interval = RealInterval::min_max(-2.0, 3.0) * interval;
-//! 
+//!
 //! assert_eq!(RealInterval::min_max(-0.5, 2.5), shifted_interval);
 //! assert_eq!(RealInterval::min_max(-4.0, 6.0), expanded_interval);
-//! 
+//!
 //! let and_interval = interval & shifted_interval;
 //! let or_interval = interval | shifted_interval;
-//! 
-//! assert_eq!(Some(RealInterval::min_max(-0.5, 2.0)), and_interval);
+//!
+//! assert_eq!(RealInterval::min_max(-0.5, 2.0), and_interval);
 //! assert_eq!(RealInterval::min_max(-1.0, 2.5), or_interval);
 //! ```
+//!
+//! By default, every arithmetic operation rounds its bounds outward (down for the
+//! minimum, up for the maximum), so the resulting interval is always guaranteed to
+//! enclose the true mathematical range even in the presence of floating-point error.
+//! Use [`RealInterval::min_max_naive`] to opt out of this guarantee in favor of
+//! plain, faster `f32` arithmetic.
+//!
+//! Disjoint intersections and arithmetic operations performed on [`RealInterval::EMPTY`]
+//! never panic; they simply produce another empty interval (the checked
+//! [`RealInterval::mul_pow2`] returns `None` for it with a positive exponent), so pipelines
+//! of set and arithmetic operations can compose without special-casing disjoint results.
+//! A union with an empty interval yields the other operand.
+//!
+//! Intervals round-trip through text via their [`Display`](std::fmt::Display) impl and a
+//! matching [`FromStr`](std::str::FromStr) impl, which also understands the `empty` and
+//! `entire` keywords.
 
 #![deny(warnings)]
 #![warn(missing_docs)]
 #![warn(clippy::missing_docs_in_private_items)]
 
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
 use std::ops::*;
 
+mod decoration;
+pub use decoration::*;
+
+/// Selects how an interval's arithmetic operations handle floating-point rounding.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round the lower bound toward negative infinity and the upper bound toward
+    /// positive infinity, so that every result is a guaranteed enclosure of the
+    /// true range. This is the default, matching the guarantee that interval
+    /// arithmetic libraries such as inari provide.
+    #[default]
+    Outward,
+    /// Compute each bound with plain floating-point arithmetic rounded to the nearest
+    /// `f32`, with no outward rounding. Faster, but the result may fail to enclose the
+    /// true range due to floating-point error.
+    Naive
+}
+
 /// Represents a closed range on the real numbers.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 pub struct RealInterval {
     /// The starting value of this range.
     pub min: f32,
     /// The ending value of this range.
-    pub max: f32
+    pub max: f32,
+    /// The rounding mode used when computing arithmetic results for this interval.
+    rounding: Rounding
 }
 
 impl RealInterval {
-    /// Creates a new interval from the given minimum and maximum.
+    /// Creates a new interval from the given minimum and maximum, using outward
+    /// rounding for all arithmetic performed on the result.
     /// The maximum must be at least as big as the minimum, or this will panic.
     pub fn min_max(min: f32, max: f32) -> Self {
         assert!(min <= max);
-        Self { min, max }
+        Self { min, max, rounding: Rounding::Outward }
+    }
+
+    /// Creates a new interval from the given minimum and maximum, opting out of
+    /// outward rounding in favor of the faster, naive `f32` arithmetic.
+    /// The maximum must be at least as big as the minimum, or this will panic.
+    pub fn min_max_naive(min: f32, max: f32) -> Self {
+        assert!(min <= max);
+        Self { min, max, rounding: Rounding::Naive }
     }
 
     /// Creates a range that contains a single point.
@@ -47,7 +93,7 @@ impl RealInterval {
         let min = value;
         let max = value;
 
-        Self { min, max }
+        Self { min, max, rounding: Rounding::Outward }
     }
 
     /// Creates a range from a point and extents around the point.
@@ -56,7 +102,41 @@ impl RealInterval {
 
         let min = value - half_extent;
         let max = value + half_extent;
-        Self { min, max }
+        Self { min, max, rounding: Rounding::Outward }
+    }
+
+    /// The empty interval, representing no values at all. This is the identity element
+    /// for [`RealInterval::hull`] (union), the absorbing element for `&` (intersection),
+    /// and the result of arithmetic operations performed on an empty interval (except the
+    /// checked [`RealInterval::mul_pow2`], which returns `None` for it with a positive exponent).
+    pub const EMPTY: Self = Self { min: f32::INFINITY, max: f32::NEG_INFINITY, rounding: Rounding::Outward };
+
+    /// The entire real line, from negative to positive infinity.
+    pub const WHOLE: Self = Self { min: f32::NEG_INFINITY, max: f32::INFINITY, rounding: Rounding::Outward };
+
+    /// The entire range of finite `f32` values, excluding the infinities.
+    pub const ENTIRE_FINITE: Self = Self { min: f32::MIN, max: f32::MAX, rounding: Rounding::Outward };
+
+    /// Reports the rounding mode used when computing arithmetic results for this interval.
+    pub fn rounding(&self) -> Rounding {
+        self.rounding
+    }
+
+    /// Determines whether this interval contains no values.
+    pub fn is_empty(self) -> bool {
+        self.min > self.max
+    }
+
+    /// Determines whether this interval spans the entire real line.
+    pub fn is_entire(self) -> bool {
+        self.min == f32::NEG_INFINITY && self.max == f32::INFINITY
+    }
+
+    /// Computes the smallest interval enclosing both this interval and another. This is
+    /// the same as `|` and never panics; since [`RealInterval::EMPTY`] has an infinite
+    /// minimum and a negative-infinite maximum, it acts as the identity element.
+    pub fn hull(self, rhs: Self) -> Self {
+        self | rhs
     }
 
     /// Determines whether the provided value lies within this interval.
@@ -66,10 +146,13 @@ impl RealInterval {
 
     /// Takes the absolute value of this range.
     pub fn abs(self) -> Self {
-        if self.min < 0.0 && 0.0 <= self.max {
+        if self.is_empty() {
+            Self { rounding: self.rounding, ..Self::EMPTY }
+        }
+        else if self.min < 0.0 && 0.0 <= self.max {
             let min = 0.0;
             let max = self.max.max(self.min.abs());
-            Self { min, max }
+            Self { min, max, rounding: self.rounding }
         }
         else {
             let a = self.min.abs();
@@ -77,7 +160,7 @@ impl RealInterval {
             let min = a.min(b);
             let max = a.max(b);
 
-            Self { min, max }
+            Self { min, max, rounding: self.rounding }
         }
     }
 
@@ -91,7 +174,7 @@ impl RealInterval {
         let min = self.min.min(rhs.min);
         let max = self.max.min(rhs.max);
 
-        Self { min, max }
+        Self { min, max, rounding: self.rounding }
     }
 
     /// Applies the maximum function between two ranges.
@@ -99,7 +182,7 @@ impl RealInterval {
         let min = self.min.max(rhs.min);
         let max = self.max.max(rhs.max);
 
-        Self { min, max }
+        Self { min, max, rounding: self.rounding }
     }
 
     /// Applies a scalar minimum to this range.
@@ -107,7 +190,7 @@ impl RealInterval {
         let min = self.min.min(value);
         let max = self.max.min(value);
 
-        Self { min, max }
+        Self { min, max, rounding: self.rounding }
     }
 
     /// Applies a scalar maximum to this range.
@@ -115,54 +198,87 @@ impl RealInterval {
         let min = self.min.max(value);
         let max = self.max.max(value);
 
-        Self { min, max }
+        Self { min, max, rounding: self.rounding }
     }
 
     /// Applies a scalar power to this range.
     /// The interval must be non-negative.
     pub fn powf(self, value: f32) -> Self {
-        let min;
-        let max;
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
 
-        assert!(self.min >= 0.0);
+        let (min, max) = match self.rounding {
+            Rounding::Outward => {
+                assert!(self.min >= 0.0);
 
-        if value > 0.0 {
-            min = self.min.powf(value);
-            max = self.max.powf(value);
-        }
-        else {
-            min = self.max.powf(value);
-            max = self.min.powf(value);
-        }
+                if value > 0.0 {
+                    (round_down((self.min as f64).powf(value as f64)), round_up((self.max as f64).powf(value as f64)))
+                }
+                else {
+                    (round_down((self.max as f64).powf(value as f64)), round_up((self.min as f64).powf(value as f64)))
+                }
+            }
+            Rounding::Naive => {
+                assert!(self.min >= 0.0);
+
+                if value > 0.0 {
+                    (self.min.powf(value), self.max.powf(value))
+                }
+                else {
+                    (self.max.powf(value), self.min.powf(value))
+                }
+            }
+        };
 
-        Self { min, max }
+        Self { min, max, rounding: self.rounding }
     }
 
     /// Applies an integral scalar power to this range.
     pub fn powi(self, value: i32) -> Self {
-        let min;
-        let max;
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
 
-        if value % 2 == 0 {
-            if self.min < 0.0 && 0.0 <= self.max {
-                min = 0.0;
-                max = self.min.powi(value).max(self.max.powi(value));
-            }
-            else if 0.0 <= self.min {
-                min = self.min.powi(value);
-                max = self.max.powi(value);
+        let (min, max) = match self.rounding {
+            Rounding::Outward => {
+                let lo = self.min as f64;
+                let hi = self.max as f64;
+
+                if value % 2 == 0 {
+                    if self.min < 0.0 && 0.0 <= self.max {
+                        (0.0, round_up(lo.powi(value).max(hi.powi(value))))
+                    }
+                    else if 0.0 <= self.min {
+                        (round_down(lo.powi(value)), round_up(hi.powi(value)))
+                    }
+                    else {
+                        (round_down(hi.powi(value)), round_up(lo.powi(value)))
+                    }
+                }
+                else {
+                    (round_down(lo.powi(value)), round_up(hi.powi(value)))
+                }
             }
-            else {
-                min = self.max.powi(value);
-                max = self.min.powi(value);
+            Rounding::Naive => {
+                if value % 2 == 0 {
+                    if self.min < 0.0 && 0.0 <= self.max {
+                        (0.0, self.min.powi(value).max(self.max.powi(value)))
+                    }
+                    else if 0.0 <= self.min {
+                        (self.min.powi(value), self.max.powi(value))
+                    }
+                    else {
+                        (self.max.powi(value), self.min.powi(value))
+                    }
+                }
+                else {
+                    (self.min.powi(value), self.max.powi(value))
+                }
             }
-        }
-        else {
-            min = self.min.powi(value);
-            max = self.max.powi(value);
-        }
+        };
 
-        Self { min, max }
+        Self { min, max, rounding: self.rounding }
     }
 
     /// Multiplies this range by the provided integer power of 2.
@@ -175,12 +291,16 @@ impl RealInterval {
     /// This function is safe, but in the case where the exponent of either minimum or maximum
     /// has an overflow, the result is not specified.
     pub fn mul_pow2_unchecked(self, value: i32) -> Self {
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+
         debug_assert!(Self::verify_ldexp(self.min, value) && Self::verify_ldexp(self.max, value), "Power-of-2 multiply caused overflow.");
 
         let min = Self::ldexp(self.min, value);
         let max = Self::ldexp(self.max, value);
 
-        Self { min, max }
+        Self { min, max, rounding: self.rounding }
     }
 
     /// Rounds this range to the nearest whole number.
@@ -188,7 +308,168 @@ impl RealInterval {
         let min = self.min.round();
         let max = self.max.round();
 
-        Self { min, max }
+        Self { min, max, rounding: self.rounding }
+    }
+
+    /// Takes the square root of this range.
+    /// The interval must be non-negative.
+    pub fn sqrt(self) -> Self {
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+
+        assert!(self.min >= 0.0);
+
+        let (min, max) = match self.rounding {
+            Rounding::Outward => (round_down((self.min as f64).sqrt()), round_up((self.max as f64).sqrt())),
+            Rounding::Naive => (self.min.sqrt(), self.max.sqrt())
+        };
+
+        Self { min, max, rounding: self.rounding }
+    }
+
+    /// Takes the natural logarithm of this range.
+    /// The interval must be non-negative; a minimum of exactly zero maps to negative infinity.
+    pub fn ln(self) -> Self {
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+
+        assert!(self.min >= 0.0);
+
+        let (min, max) = match self.rounding {
+            Rounding::Outward => {
+                let lo = if self.min == 0.0 { f64::NEG_INFINITY } else { (self.min as f64).ln() };
+                (round_down(lo), round_up((self.max as f64).ln()))
+            }
+            Rounding::Naive => {
+                let lo = if self.min == 0.0 { f32::NEG_INFINITY } else { self.min.ln() };
+                (lo, self.max.ln())
+            }
+        };
+
+        Self { min, max, rounding: self.rounding }
+    }
+
+    /// Takes `e` raised to the power of this range.
+    pub fn exp(self) -> Self {
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+
+        let (min, max) = match self.rounding {
+            Rounding::Outward => (round_down((self.min as f64).exp()), round_up((self.max as f64).exp())),
+            Rounding::Naive => (self.min.exp(), self.max.exp())
+        };
+
+        Self { min, max, rounding: self.rounding }
+    }
+
+    /// Takes the sine of this range.
+    pub fn sin(self) -> Self {
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+
+        let (lo, hi) = periodic_bounds(self.min as f64, self.max as f64, FRAC_PI_2, -FRAC_PI_2, f64::sin);
+
+        let (min, max) = match self.rounding {
+            Rounding::Outward => (round_down(lo), round_up(hi)),
+            Rounding::Naive => (lo as f32, hi as f32)
+        };
+
+        Self { min, max, rounding: self.rounding }
+    }
+
+    /// Takes the cosine of this range.
+    pub fn cos(self) -> Self {
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+
+        let (lo, hi) = periodic_bounds(self.min as f64, self.max as f64, 0.0, PI, f64::cos);
+
+        let (min, max) = match self.rounding {
+            Rounding::Outward => (round_down(lo), round_up(hi)),
+            Rounding::Naive => (lo as f32, hi as f32)
+        };
+
+        Self { min, max, rounding: self.rounding }
+    }
+
+    /// Divides this interval by another, mirroring inari's two-output division.
+    /// When the denominator does not contain zero, the quotient set is a single
+    /// interval and is returned in the first slot. When the denominator straddles
+    /// zero, the quotient set may be unbounded or split into two disjoint pieces,
+    /// which are returned in the first and (if present) second slot.
+    pub fn div_multi(self, rhs: Self) -> [Option<Self>; 2] {
+        if self.is_empty() || rhs.is_empty() {
+            return [Some(Self { rounding: self.rounding, ..Self::EMPTY }), None];
+        }
+
+        let Self { min: a, max: b, rounding } = self;
+        let Self { min: c, max: d, .. } = rhs;
+
+        let bounds = |lo: f64, hi: f64| match rounding {
+            Rounding::Outward => (round_down(lo), round_up(hi)),
+            Rounding::Naive => (lo as f32, hi as f32)
+        };
+        let whole = |min: f32, max: f32| Some(Self { min, max, rounding });
+
+        if 0.0 < c || d < 0.0 {
+            let (a, b, c, d) = (a as f64, b as f64, c as f64, d as f64);
+            let q1 = a / c;
+            let q2 = a / d;
+            let q3 = b / c;
+            let q4 = b / d;
+
+            let lo = q1.min(q2).min(q3).min(q4);
+            let hi = q1.max(q2).max(q3).max(q4);
+            let (min, max) = bounds(lo, hi);
+
+            [whole(min, max), None]
+        }
+        else if a == 0.0 && b == 0.0 {
+            [Some(Self::point(0.0)), None]
+        }
+        else if b < 0.0 {
+            if d == 0.0 {
+                let (min, _) = bounds(b as f64 / c as f64, f64::INFINITY);
+                [whole(min, f32::INFINITY), None]
+            }
+            else if c < 0.0 {
+                let (_, hi) = bounds(f64::NEG_INFINITY, b as f64 / d as f64);
+                let (lo, _) = bounds(b as f64 / c as f64, f64::INFINITY);
+                [whole(f32::NEG_INFINITY, hi), whole(lo, f32::INFINITY)]
+            }
+            else {
+                let (_, hi) = bounds(f64::NEG_INFINITY, b as f64 / d as f64);
+                [whole(f32::NEG_INFINITY, hi), None]
+            }
+        }
+        else if a > 0.0 {
+            if c == 0.0 {
+                if d == 0.0 {
+                    [whole(f32::NEG_INFINITY, f32::INFINITY), None]
+                }
+                else {
+                    let (lo, _) = bounds(a as f64 / d as f64, f64::INFINITY);
+                    [whole(lo, f32::INFINITY), None]
+                }
+            }
+            else if d > 0.0 {
+                let (_, hi) = bounds(f64::NEG_INFINITY, a as f64 / c as f64);
+                let (lo, _) = bounds(a as f64 / d as f64, f64::INFINITY);
+                [whole(f32::NEG_INFINITY, hi), whole(lo, f32::INFINITY)]
+            }
+            else {
+                let (_, hi) = bounds(f64::NEG_INFINITY, a as f64 / c as f64);
+                [whole(f32::NEG_INFINITY, hi), None]
+            }
+        }
+        else {
+            [whole(f32::NEG_INFINITY, f32::INFINITY), None]
+        }
     }
 
     /// Verifies that multiplying the given float by the provided
@@ -196,7 +477,7 @@ impl RealInterval {
     fn verify_ldexp(a: f32, exp: i32) -> bool {
         let bits = a.to_bits();
         let exponent = ((bits >> 23) & 0xff) as i32;
-        
+
         if exp > 0 {
             exp <= 255 && exponent + exp <= 255
         }
@@ -213,14 +494,59 @@ impl RealInterval {
     }
 }
 
+/// Rounds the given double down to the largest `f32` that is less than or equal to it.
+fn round_down(value: f64) -> f32 {
+    if value.is_nan() {
+        return f32::NAN;
+    }
+
+    let approx = value as f32;
+    if (approx as f64) <= value { approx } else { approx.next_down() }
+}
+
+/// Rounds the given double up to the smallest `f32` that is greater than or equal to it.
+fn round_up(value: f64) -> f32 {
+    if value.is_nan() {
+        return f32::NAN;
+    }
+
+    let approx = value as f32;
+    if (approx as f64) >= value { approx } else { approx.next_up() }
+}
+
+/// Determines whether some `phase + 2*pi*k`, for an integer `k`, lies within `[a, b]`.
+fn reaches_phase(a: f64, b: f64, phase: f64) -> bool {
+    ((b - phase) / TAU).floor() >= ((a - phase) / TAU).ceil()
+}
+
+/// Computes the tightest enclosure of a periodic function with period `2*pi` over `[a, b]`,
+/// given the phases at which it reaches its maximum (`1.0`) and minimum (`-1.0`). Widens to
+/// the full `[-1, 1]` range whenever `[a, b]` spans more than a full period, to guard against
+/// the precision loss that evaluating `f` at huge arguments would otherwise cause.
+fn periodic_bounds(a: f64, b: f64, max_phase: f64, min_phase: f64, f: fn(f64) -> f64) -> (f64, f64) {
+    if b - a > TAU {
+        return (-1.0, 1.0);
+    }
+
+    let fa = f(a);
+    let fb = f(b);
+
+    let max = if reaches_phase(a, b, max_phase) { 1.0 } else { fa.max(fb) };
+    let min = if reaches_phase(a, b, min_phase) { -1.0 } else { fa.min(fb) };
+
+    (min, max)
+}
+
 impl BitAnd for RealInterval {
-    type Output = Option<Self>;
+    type Output = Self;
 
+    /// Intersects this interval with another. Returns [`RealInterval::EMPTY`] (rather than
+    /// panicking or returning `None`) when the two intervals are disjoint.
     fn bitand(self, rhs: Self) -> Self::Output {
         let min = f32::max(self.min, rhs.min);
         let max = f32::min(self.max, rhs.max);
 
-        (min <= max).then_some(Self { min, max })
+        if min <= max { Self { min, max, rounding: self.rounding } } else { Self { rounding: self.rounding, ..Self::EMPTY } }
     }
 }
 
@@ -231,7 +557,7 @@ impl BitOr for RealInterval {
         let min = self.min.min(rhs.min);
         let max = self.max.max(rhs.max);
 
-        Self { min, max }
+        Self { min, max, rounding: self.rounding }
     }
 }
 
@@ -239,9 +565,15 @@ impl Add<f32> for RealInterval {
     type Output = Self;
 
     fn add(self, rhs: f32) -> Self::Output {
-        let min = self.min + rhs;
-        let max = self.max + rhs;
-        Self { min, max }
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+
+        let (min, max) = match self.rounding {
+            Rounding::Outward => (round_down(self.min as f64 + rhs as f64), round_up(self.max as f64 + rhs as f64)),
+            Rounding::Naive => (self.min + rhs, self.max + rhs)
+        };
+        Self { min, max, rounding: self.rounding }
     }
 }
 
@@ -249,9 +581,15 @@ impl Sub<f32> for RealInterval {
     type Output = Self;
 
     fn sub(self, rhs: f32) -> Self::Output {
-        let min = self.min - rhs;
-        let max = self.max - rhs;
-        Self { min, max }
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+
+        let (min, max) = match self.rounding {
+            Rounding::Outward => (round_down(self.min as f64 - rhs as f64), round_up(self.max as f64 - rhs as f64)),
+            Rounding::Naive => (self.min - rhs, self.max - rhs)
+        };
+        Self { min, max, rounding: self.rounding }
     }
 }
 
@@ -259,16 +597,20 @@ impl Mul<f32> for RealInterval {
     type Output = Self;
 
     fn mul(self, rhs: f32) -> Self::Output {
-        if rhs >= 0.0 {
-            let min = rhs * self.min;
-            let max = rhs * self.max;
-            Self { min, max }
-        }
-        else {
-            let min = rhs * self.max;
-            let max = rhs * self.min;
-            Self { min, max }
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
         }
+
+        let (min, max) = match self.rounding {
+            Rounding::Outward => {
+                let (lo, hi) = (self.min as f64 * rhs as f64, self.max as f64 * rhs as f64);
+                if rhs >= 0.0 { (round_down(lo), round_up(hi)) } else { (round_down(hi), round_up(lo)) }
+            }
+            Rounding::Naive => {
+                if rhs >= 0.0 { (rhs * self.min, rhs * self.max) } else { (rhs * self.max, rhs * self.min) }
+            }
+        };
+        Self { min, max, rounding: self.rounding }
     }
 }
 
@@ -276,16 +618,20 @@ impl Mul<RealInterval> for f32 {
     type Output = RealInterval;
 
     fn mul(self, rhs: RealInterval) -> Self::Output {
-        if self >= 0.0 {
-            let min = self * rhs.min;
-            let max = self * rhs.max;
-            RealInterval { min, max }
-        }
-        else {
-            let min = self * rhs.max;
-            let max = self * rhs.min;
-            RealInterval { min, max }
+        if rhs.is_empty() {
+            return RealInterval { rounding: rhs.rounding, ..RealInterval::EMPTY };
         }
+
+        let (min, max) = match rhs.rounding {
+            Rounding::Outward => {
+                let (lo, hi) = (self as f64 * rhs.min as f64, self as f64 * rhs.max as f64);
+                if self >= 0.0 { (round_down(lo), round_up(hi)) } else { (round_down(hi), round_up(lo)) }
+            }
+            Rounding::Naive => {
+                if self >= 0.0 { (self * rhs.min, self * rhs.max) } else { (self * rhs.max, self * rhs.min) }
+            }
+        };
+        RealInterval { min, max, rounding: rhs.rounding }
     }
 }
 
@@ -293,9 +639,15 @@ impl Add for RealInterval {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let min = self.min + rhs.min;
-        let max = self.max + rhs.max;
-        Self { min, max }
+        if self.is_empty() || rhs.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+
+        let (min, max) = match self.rounding {
+            Rounding::Outward => (round_down(self.min as f64 + rhs.min as f64), round_up(self.max as f64 + rhs.max as f64)),
+            Rounding::Naive => (self.min + rhs.min, self.max + rhs.max)
+        };
+        Self { min, max, rounding: self.rounding }
     }
 }
 
@@ -303,9 +655,15 @@ impl Sub for RealInterval {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let min = self.min - rhs.max;
-        let max = self.max - rhs.min;
-        Self { min, max }
+        if self.is_empty() || rhs.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+
+        let (min, max) = match self.rounding {
+            Rounding::Outward => (round_down(self.min as f64 - rhs.max as f64), round_up(self.max as f64 - rhs.min as f64)),
+            Rounding::Naive => (self.min - rhs.max, self.max - rhs.min)
+        };
+        Self { min, max, rounding: self.rounding }
     }
 }
 
@@ -313,7 +671,20 @@ impl Mul for RealInterval {
     type Output = Self;
 
     fn mul(self, rhs: RealInterval) -> Self::Output {
-        (self.min * rhs) | (self.max * rhs)
+        if self.is_empty() || rhs.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+
+        let (a, b, c, d) = (self.min as f64, self.max as f64, rhs.min as f64, rhs.max as f64);
+        let products = [a * c, a * d, b * c, b * d];
+        let lo = products.into_iter().fold(f64::INFINITY, f64::min);
+        let hi = products.into_iter().fold(f64::NEG_INFINITY, f64::max);
+
+        let (min, max) = match self.rounding {
+            Rounding::Outward => (round_down(lo), round_up(hi)),
+            Rounding::Naive => (lo as f32, hi as f32)
+        };
+        Self { min, max, rounding: self.rounding }
     }
 }
 
@@ -321,15 +692,136 @@ impl Neg for RealInterval {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+
         let min = -self.max;
         let max = -self.min;
-        Self { min, max }
+        Self { min, max, rounding: self.rounding }
+    }
+}
+
+impl Div<f32> for RealInterval {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        if self.is_empty() {
+            return Self { rounding: self.rounding, ..Self::EMPTY };
+        }
+        if rhs == 0.0 {
+            return match self.div_multi(RealInterval::point(rhs)) {
+                [Some(first), Some(second)] => first.hull(second),
+                [Some(first), None] => first,
+                _ => unreachable!("div_multi always produces at least one interval")
+            };
+        }
+
+        let (min, max) = match self.rounding {
+            Rounding::Outward => {
+                let (lo, hi) = (self.min as f64 / rhs as f64, self.max as f64 / rhs as f64);
+                if rhs >= 0.0 { (round_down(lo), round_up(hi)) } else { (round_down(hi), round_up(lo)) }
+            }
+            Rounding::Naive => {
+                if rhs >= 0.0 { (self.min / rhs, self.max / rhs) } else { (self.max / rhs, self.min / rhs) }
+            }
+        };
+        Self { min, max, rounding: self.rounding }
+    }
+}
+
+impl Div for RealInterval {
+    type Output = Self;
+
+    /// Divides this interval by another, returning the tightest single interval
+    /// that encloses the true quotient set. If the true result is split into two
+    /// disjoint pieces (because the denominator straddles zero), the returned
+    /// interval is the hull of both pieces; use [`RealInterval::div_multi`] to
+    /// keep the pieces separate.
+    fn div(self, rhs: Self) -> Self::Output {
+        match self.div_multi(rhs) {
+            [Some(first), Some(second)] => first.hull(second),
+            [Some(first), None] => first,
+            _ => unreachable!("div_multi always produces at least one interval")
+        }
     }
 }
 
 impl std::fmt::Display for RealInterval {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("[{}, {}]", self.min, self.max))
+        if self.is_empty() {
+            f.write_str("empty")
+        }
+        else if self.is_entire() {
+            f.write_str("entire")
+        }
+        else {
+            f.write_fmt(format_args!("[{}, {}]", self.min, self.max))
+        }
+    }
+}
+
+/// Describes why a [`RealInterval`] could not be parsed from a string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseIntervalError {
+    /// The input was missing its opening `[` or closing `]` (and wasn't `empty` or `entire`).
+    MissingBracket,
+    /// One of the two bounds was missing, was not a valid `f32`, or was `NaN`.
+    BadBound,
+    /// The minimum bound was greater than the maximum bound.
+    InvertedBounds
+}
+
+impl std::fmt::Display for ParseIntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            ParseIntervalError::MissingBracket => "interval is missing its enclosing '[' and ']'",
+            ParseIntervalError::BadBound => "interval bound is not a valid number",
+            ParseIntervalError::InvertedBounds => "interval minimum is greater than its maximum"
+        };
+
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for ParseIntervalError {}
+
+impl std::str::FromStr for RealInterval {
+    type Err = ParseIntervalError;
+
+    /// Parses the `[min, max]` grammar printed by [`Display`](std::fmt::Display), plus the
+    /// keywords `empty` and `entire`. Surrounding whitespace is tolerated; trailing garbage
+    /// after the closing bracket is not.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+
+        if trimmed.eq_ignore_ascii_case("empty") {
+            return Ok(Self::EMPTY);
+        }
+        if trimmed.eq_ignore_ascii_case("entire") {
+            return Ok(Self::WHOLE);
+        }
+
+        let inner = trimmed
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+            .ok_or(ParseIntervalError::MissingBracket)?;
+
+        let mut bounds = inner.splitn(2, ',');
+        let min_str = bounds.next().ok_or(ParseIntervalError::BadBound)?;
+        let max_str = bounds.next().ok_or(ParseIntervalError::BadBound)?;
+
+        let min: f32 = min_str.trim().parse().map_err(|_| ParseIntervalError::BadBound)?;
+        let max: f32 = max_str.trim().parse().map_err(|_| ParseIntervalError::BadBound)?;
+
+        if min.is_nan() || max.is_nan() {
+            return Err(ParseIntervalError::BadBound);
+        }
+        if min > max {
+            return Err(ParseIntervalError::InvertedBounds);
+        }
+
+        Ok(Self::min_max(min, max))
     }
 }
 
@@ -349,7 +841,109 @@ mod tests {
         let and_interval = interval & shifted_interval;
         let or_interval = interval | shifted_interval;
 
-        assert_eq!(Some(RealInterval::min_max(-0.5, 2.0)), and_interval);
+        assert_eq!(RealInterval::min_max(-0.5, 2.0), and_interval);
         assert_eq!(RealInterval::min_max(-1.0, 2.5), or_interval);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_division() {
+        let x = RealInterval::min_max(1.0, 2.0);
+        let y = RealInterval::min_max(4.0, 8.0);
+        assert_eq!(RealInterval::min_max(0.125, 0.5), x / y);
+
+        let straddling = RealInterval::min_max(-2.0, 4.0);
+        let split = RealInterval::min_max(-1.0, -1.0).div_multi(straddling);
+        assert_eq!([Some(RealInterval::min_max(f32::NEG_INFINITY, -0.25)), Some(RealInterval::min_max(0.5, f32::INFINITY))], split);
+        assert_eq!(RealInterval::min_max(f32::NEG_INFINITY, f32::INFINITY), RealInterval::min_max(-1.0, -1.0) / straddling);
+
+        let zero = RealInterval::point(0.0);
+        assert_eq!([Some(RealInterval::point(0.0)), None], zero.div_multi(straddling));
+
+        // Dividing by the exact zero point must degrade to `entire` rather than producing
+        // a bogus point interval at infinity.
+        assert_eq!([Some(RealInterval::WHOLE), None], x.div_multi(RealInterval::point(0.0)));
+        assert_eq!(RealInterval::WHOLE, x / RealInterval::point(0.0));
+
+        // Scalar division by zero gets the same treatment as interval division.
+        assert_eq!(RealInterval::WHOLE, x / 0.0);
+        assert_eq!(RealInterval::WHOLE, (-x) / 0.0);
+    }
+
+    #[test]
+    fn test_outward_rounding_encloses_true_sum() {
+        let a = RealInterval::point(0.1);
+        let b = RealInterval::point(0.2);
+        let sum = a + b;
+
+        assert!(sum.min as f64 <= 0.1 + 0.2);
+        assert!(sum.max as f64 >= 0.1 + 0.2);
+        assert_ne!(sum.min, sum.max, "rounding should widen the interval rather than collapse it to a point");
+
+        let naive_sum = RealInterval::min_max_naive(0.1, 0.1) + RealInterval::min_max_naive(0.2, 0.2);
+        assert_eq!(naive_sum.min, naive_sum.max);
+    }
+
+    #[test]
+    fn test_outward_rounding_encloses_true_product() {
+        let outward = RealInterval::min_max(0.1, 0.1);
+        let naive = RealInterval::min_max_naive(0.2, 0.2);
+        let product = outward * naive;
+
+        assert!(product.min as f64 <= 0.1 * 0.2);
+        assert!(product.max as f64 >= 0.1 * 0.2);
+        assert_ne!(product.min, product.max, "rounding should widen the interval rather than collapse it to a point");
+    }
+
+    #[test]
+    fn test_transcendental_functions() {
+        let one_to_four = RealInterval::min_max(1.0, 4.0);
+        assert_eq!(RealInterval::min_max(1.0, 2.0), one_to_four.sqrt());
+
+        let with_zero = RealInterval::min_max(0.0, 1.0);
+        assert_eq!(f32::NEG_INFINITY, with_zero.ln().min);
+
+        let zero = RealInterval::point(0.0);
+        assert_eq!(RealInterval::point(1.0), zero.exp());
+
+        let full_period = RealInterval::min_max(0.0, std::f32::consts::TAU);
+        assert_eq!(RealInterval::min_max(-1.0, 1.0), full_period.sin());
+
+        let half_period = RealInterval::min_max(0.0, std::f32::consts::PI);
+        assert_eq!(RealInterval::min_max(-1.0, 1.0), half_period.cos());
+    }
+
+    #[test]
+    fn test_empty_and_whole() {
+        assert!(RealInterval::EMPTY.is_empty());
+        assert!(!RealInterval::WHOLE.is_empty());
+        assert!(RealInterval::WHOLE.is_entire());
+
+        let a = RealInterval::min_max(0.0, 1.0);
+        let b = RealInterval::min_max(2.0, 3.0);
+        let disjoint = a & b;
+
+        assert!(disjoint.is_empty());
+        assert_eq!(RealInterval::min_max(0.0, 3.0), a.hull(b));
+        assert_eq!(a, a.hull(RealInterval::EMPTY));
+
+        assert!((RealInterval::EMPTY + a).is_empty());
+        assert!(RealInterval::EMPTY.sqrt().is_empty());
+        assert!((RealInterval::EMPTY / a).is_empty());
+    }
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        let interval = RealInterval::min_max(-1.5, 2.5);
+        assert_eq!(interval, interval.to_string().parse().unwrap());
+
+        assert_eq!(RealInterval::EMPTY, "empty".parse().unwrap());
+        assert_eq!(RealInterval::WHOLE, " ENTIRE ".parse().unwrap());
+        assert_eq!(Ok(RealInterval::min_max(-1.0, 2.0)), "  [ -1, 2 ]  ".parse());
+
+        assert_eq!(Err(ParseIntervalError::MissingBracket), "-1, 2".parse::<RealInterval>());
+        assert_eq!(Err(ParseIntervalError::BadBound), "[a, 2]".parse::<RealInterval>());
+        assert_eq!(Err(ParseIntervalError::InvertedBounds), "[2, 1]".parse::<RealInterval>());
+        assert_eq!(Err(ParseIntervalError::BadBound), "[NaN, NaN]".parse::<RealInterval>());
+        assert_eq!(Err(ParseIntervalError::BadBound), "[NaN, 2]".parse::<RealInterval>());
+    }
+}